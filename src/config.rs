@@ -0,0 +1,98 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::PathBuf;
+
+use serde::Deserialize;
+
+use crate::Shortcut;
+
+#[derive(Deserialize)]
+struct ConfigShortcut {
+    key: String,
+    description: String,
+}
+
+/// `~/.config/bk/shortcuts.toml`, or `None` if `$HOME` isn't set.
+fn config_path() -> Option<PathBuf> {
+    let home = std::env::var_os("HOME")?;
+    Some(
+        PathBuf::from(home)
+            .join(".config")
+            .join("bk")
+            .join("shortcuts.toml"),
+    )
+}
+
+/// Loads the user's shortcut config file, if any, and merges it into `shortcuts`.
+pub fn merge_user_shortcuts(shortcuts: &mut HashMap<String, Vec<Shortcut>>) {
+    let Some(path) = config_path() else {
+        return;
+    };
+    let Ok(contents) = fs::read_to_string(path) else {
+        return;
+    };
+    let Ok(config) = toml::from_str::<HashMap<String, Vec<ConfigShortcut>>>(&contents) else {
+        return;
+    };
+
+    merge_entries(shortcuts, config);
+}
+
+/// Appends new keys, overriding a built-in one with the same key.
+fn merge_entries(
+    shortcuts: &mut HashMap<String, Vec<Shortcut>>,
+    config: HashMap<String, Vec<ConfigShortcut>>,
+) {
+    for (category, entries) in config {
+        let bucket = shortcuts.entry(category).or_default();
+        for entry in entries {
+            match bucket.iter_mut().find(|s| s.key == entry.key) {
+                Some(existing) => existing.description = entry.description,
+                None => bucket.push(Shortcut::new(entry.key, entry.description)),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_merge_overrides_existing_key_in_same_category() {
+        let mut shortcuts = HashMap::from([(
+            "Movement".to_string(),
+            vec![Shortcut::new("ctrl+a", "Go to line start (home)")],
+        )]);
+        let config = HashMap::from([(
+            "Movement".to_string(),
+            vec![ConfigShortcut {
+                key: "ctrl+a".to_string(),
+                description: "Jump to start of line".to_string(),
+            }],
+        )]);
+
+        merge_entries(&mut shortcuts, config);
+
+        let movement = &shortcuts["Movement"];
+        assert_eq!(movement.len(), 1);
+        assert_eq!(movement[0].description, "Jump to start of line");
+    }
+
+    #[test]
+    fn test_merge_appends_new_category_and_key() {
+        let mut shortcuts = HashMap::new();
+        let config = HashMap::from([(
+            "Custom".to_string(),
+            vec![ConfigShortcut {
+                key: "ctrl+g".to_string(),
+                description: "Run my tool".to_string(),
+            }],
+        )]);
+
+        merge_entries(&mut shortcuts, config);
+
+        assert_eq!(shortcuts["Custom"].len(), 1);
+        assert_eq!(shortcuts["Custom"][0].key, "ctrl+g");
+    }
+}
@@ -1,7 +1,10 @@
+mod config;
+mod tui;
+
 use clap::Parser;
 use std::collections::HashMap;
 use std::fs;
-use std::io::{self, Write};
+use std::io::{self, IsTerminal, Write};
 use tabled::{
     Table, Tabled,
     settings::{Modify, Panel, Remove, Style, Width, object::Columns},
@@ -21,7 +24,9 @@ Run without flags to show all shortcuts organized by category.",
     bk             Show all shortcuts
     bk -m          Show movement shortcuts only
     bk -me         Show movement and edit shortcuts (chained)
-    bk -e -r       Show edit and recall shortcuts (separate)"
+    bk -e -r       Show edit and recall shortcuts (separate)
+    bk -v          Show Vi-mode (set -o vi) shortcuts
+    bk -i          Launch the interactive shortcut browser"
 )]
 struct Args {
     /// Show movement related shortcuts
@@ -40,27 +45,46 @@ struct Args {
     #[arg(short, long)]
     process: bool,
 
+    /// Fuzzy search shortcut keys and descriptions
+    #[arg(short, long, value_name = "QUERY", conflicts_with_all = ["key", "vi", "interactive"])]
+    find: Option<String>,
+
+    /// Explain what a key chord does (accepts ctrl+a, C-a, or ^a)
+    #[arg(short, long, value_name = "CHORD", conflicts_with_all = ["find", "vi", "interactive"])]
+    key: Option<String>,
+
+    /// Show Vi-mode bindings (set -o vi) instead of the Emacs defaults
+    #[arg(short, long, conflicts_with_all = ["find", "key", "interactive"])]
+    vi: bool,
+
+    /// Launch an interactive, incrementally-filtered shortcut browser
+    #[arg(short, long, conflicts_with_all = ["find", "key", "vi"])]
+    interactive: bool,
+
     /// Remove the bk binary from your system
     #[arg(long)]
     uninstall: bool,
 }
 
 #[derive(Clone, Tabled)]
-struct Shortcut {
-    description: &'static str,
-    key: &'static str,
+pub(crate) struct Shortcut {
+    pub(crate) description: String,
+    pub(crate) key: String,
 }
 
 impl Shortcut {
-    fn new(key: &'static str, description: &'static str) -> Self {
-        Self { key, description }
+    pub(crate) fn new(key: impl Into<String>, description: impl Into<String>) -> Self {
+        Self {
+            key: key.into(),
+            description: description.into(),
+        }
     }
 }
 
-fn init_shortcuts() -> HashMap<&'static str, Vec<Shortcut>> {
+fn init_shortcuts() -> HashMap<String, Vec<Shortcut>> {
     HashMap::from([
         (
-            "Movement",
+            "Movement".to_string(),
             vec![
                 Shortcut::new("ctrl+a", "Go to line start (home)"),
                 Shortcut::new("ctrl+e", "Go to line end (end)"),
@@ -74,7 +98,7 @@ fn init_shortcuts() -> HashMap<&'static str, Vec<Shortcut>> {
             ],
         ),
         (
-            "Edit",
+            "Edit".to_string(),
             vec![
                 Shortcut::new("ctrl+l", "Clear screen"),
                 Shortcut::new("alt+del", "Delete word before cursor"),
@@ -97,7 +121,7 @@ fn init_shortcuts() -> HashMap<&'static str, Vec<Shortcut>> {
             ],
         ),
         (
-            "Recall",
+            "Recall".to_string(),
             vec![
                 Shortcut::new("ctrl+r", "Search command history as you type"),
                 Shortcut::new("ctrl+p", "Previous command in history (walk back)"),
@@ -128,7 +152,7 @@ fn init_shortcuts() -> HashMap<&'static str, Vec<Shortcut>> {
             ],
         ),
         (
-            "Process",
+            "Process".to_string(),
             vec![
                 Shortcut::new("ctrl+c", "Kill/Interrupt current process (SIGINT)"),
                 Shortcut::new("ctrl+s", "Stop screen output (scroll with PgUp/PgDn)"),
@@ -140,6 +164,184 @@ fn init_shortcuts() -> HashMap<&'static str, Vec<Shortcut>> {
     ])
 }
 
+/// Vi-mode bindings, split into normal and insert mode categories.
+fn init_vi_shortcuts() -> HashMap<String, Vec<Shortcut>> {
+    HashMap::from([
+        (
+            "Vi Normal".to_string(),
+            vec![
+                Shortcut::new("esc", "Switch to command (normal) mode"),
+                Shortcut::new("h", "Move cursor left"),
+                Shortcut::new("l", "Move cursor right"),
+                Shortcut::new("w", "Move forward one word"),
+                Shortcut::new("b", "Move back one word"),
+                Shortcut::new("0", "Move to start of line"),
+                Shortcut::new("$", "Move to end of line"),
+                Shortcut::new("dd", "Delete (cut) the current line"),
+                Shortcut::new("cc", "Change (replace) the current line"),
+                Shortcut::new("v", "Edit the command line in $EDITOR"),
+            ],
+        ),
+        (
+            "Vi Insert".to_string(),
+            vec![
+                Shortcut::new("i", "Insert before cursor"),
+                Shortcut::new("a", "Insert after cursor"),
+            ],
+        ),
+    ])
+}
+
+fn build_vi_output(shortcuts: &HashMap<String, Vec<Shortcut>>) -> String {
+    let mut output = String::new();
+
+    for category in ["Vi Normal", "Vi Insert"] {
+        if let Some(entries) = shortcuts.get(category) {
+            output.push_str(&format_table(entries, category));
+            output.push_str("\n\n");
+        }
+    }
+
+    output
+}
+
+#[derive(Clone, Tabled)]
+struct SearchHit {
+    category: String,
+    key: String,
+    description: String,
+}
+
+/// Subsequence match score, or `None` if `query` doesn't match `candidate`.
+pub(crate) fn fuzzy_score(query: &str, candidate: &str) -> Option<i32> {
+    if query.is_empty() {
+        return Some(0);
+    }
+
+    let query: Vec<char> = query.to_lowercase().chars().collect();
+    let candidate: Vec<char> = candidate.to_lowercase().chars().collect();
+
+    let mut score = 0i32;
+    let mut q_idx = 0;
+    let mut last_match: Option<usize> = None;
+
+    for (c_idx, &c) in candidate.iter().enumerate() {
+        if q_idx >= query.len() {
+            break;
+        }
+        if c != query[q_idx] {
+            continue;
+        }
+
+        match last_match {
+            Some(last) if c_idx == last + 1 => score += 15,
+            Some(last) => score -= (c_idx - last - 1) as i32,
+            None => score -= c_idx as i32,
+        }
+
+        let at_boundary = c_idx == 0 || matches!(candidate[c_idx - 1], ' ' | '+' | '-');
+        if at_boundary {
+            score += 10;
+        }
+
+        last_match = Some(c_idx);
+        q_idx += 1;
+    }
+
+    (q_idx == query.len()).then_some(score)
+}
+
+/// Ranks shortcuts by best of `key`/`description` score, ties broken by
+/// the matched field's length.
+fn search_shortcuts(shortcuts: &HashMap<String, Vec<Shortcut>>, query: &str) -> Vec<SearchHit> {
+    let mut hits: Vec<(i32, usize, SearchHit)> = shortcuts
+        .iter()
+        .flat_map(|(category, entries)| entries.iter().map(move |s| (category, s)))
+        .filter_map(|(category, s)| {
+            let key_score = fuzzy_score(query, &s.key);
+            let desc_score = fuzzy_score(query, &s.description);
+            let (score, match_len) = match (key_score, desc_score) {
+                (Some(k), Some(d)) if d > k => (d, s.description.len()),
+                (Some(k), _) => (k, s.key.len()),
+                (None, Some(d)) => (d, s.description.len()),
+                (None, None) => return None,
+            };
+            Some((
+                score,
+                match_len,
+                SearchHit {
+                    category: category.clone(),
+                    key: s.key.clone(),
+                    description: s.description.clone(),
+                },
+            ))
+        })
+        .collect();
+
+    hits.sort_by(|(score_a, len_a, _), (score_b, len_b, _)| score_b.cmp(score_a).then_with(|| len_a.cmp(len_b)));
+
+    hits.into_iter().map(|(_, _, hit)| hit).collect()
+}
+
+/// Normalizes `^a`/`C-a`/`M-a` spellings to the `ctrl+`/`alt+` form.
+fn normalize_chord(input: &str) -> String {
+    let lower = input.trim().to_lowercase();
+
+    if let Some(rest) = lower.strip_prefix('^') {
+        format!("ctrl+{rest}")
+    } else if let Some(rest) = lower.strip_prefix("c-") {
+        format!("ctrl+{rest}")
+    } else if let Some(rest) = lower.strip_prefix("m-") {
+        format!("alt+{rest}")
+    } else {
+        lower
+    }
+}
+
+#[derive(Clone, Tabled)]
+struct KeyMatch {
+    category: String,
+    description: String,
+}
+
+/// Reverse lookup: every `Shortcut` whose `key` matches `chord`, across all categories.
+fn explain_key(shortcuts: &HashMap<String, Vec<Shortcut>>, chord: &str) -> Vec<KeyMatch> {
+    let chord = normalize_chord(chord);
+
+    let mut hits: Vec<KeyMatch> = shortcuts
+        .iter()
+        .flat_map(|(category, entries)| {
+            entries.iter().filter_map(|s| {
+                (normalize_chord(&s.key) == chord).then(|| KeyMatch {
+                    category: category.clone(),
+                    description: s.description.clone(),
+                })
+            })
+        })
+        .collect();
+
+    hits.sort_by(|a, b| a.category.cmp(&b.category));
+    hits
+}
+
+fn format_key_table(hits: &[KeyMatch], chord: &str) -> String {
+    Table::new(hits)
+        .with(Modify::new(Columns::first()).with(Width::increase(18)))
+        .with(Style::blank())
+        .with(Panel::header(format!("Matches for '{}'", chord)))
+        .with(Remove::row(tabled::settings::object::Rows::one(1)))
+        .to_string()
+}
+
+fn format_search_table(hits: &[SearchHit]) -> String {
+    Table::new(hits)
+        .with(Modify::new(Columns::first()).with(Width::increase(18)))
+        .with(Style::blank())
+        .with(Panel::header("Search results"))
+        .with(Remove::row(tabled::settings::object::Rows::one(1)))
+        .to_string()
+}
+
 fn format_table(shortcuts: &[Shortcut], category: &str) -> String {
     Table::new(shortcuts)
         .with(Modify::new(Columns::first()).with(Width::increase(57)))
@@ -149,7 +351,7 @@ fn format_table(shortcuts: &[Shortcut], category: &str) -> String {
         .to_string()
 }
 
-fn build_output(args: &Args, shortcuts: &HashMap<&str, Vec<Shortcut>>) -> String {
+fn build_output(args: &Args, shortcuts: &HashMap<String, Vec<Shortcut>>) -> String {
     let categories = [
         ("Movement", args.movement),
         ("Edit", args.edit),
@@ -162,8 +364,26 @@ fn build_output(args: &Args, shortcuts: &HashMap<&str, Vec<Shortcut>>) -> String
 
     for (category, flag) in categories {
         if show_all || flag {
-            if let Some(shortcuts) = shortcuts.get(category) {
-                output.push_str(&format_table(shortcuts, category));
+            if let Some(entries) = shortcuts.get(category) {
+                output.push_str(&format_table(entries, category));
+                output.push_str("\n\n");
+            }
+        }
+    }
+
+    // User-defined categories from shortcuts.toml fall outside the fixed
+    // set of CLI flags, so they only show up when printing everything.
+    if show_all {
+        let known: [&str; 4] = [categories[0].0, categories[1].0, categories[2].0, categories[3].0];
+        let mut extra: Vec<&String> = shortcuts
+            .keys()
+            .filter(|category| !known.contains(&category.as_str()))
+            .collect();
+        extra.sort();
+
+        for category in extra {
+            if let Some(entries) = shortcuts.get(category) {
+                output.push_str(&format_table(entries, category));
                 output.push_str("\n\n");
             }
         }
@@ -202,7 +422,46 @@ fn main() {
         return;
     }
 
-    let shortcuts = init_shortcuts();
+    if args.vi {
+        print!("{}", build_vi_output(&init_vi_shortcuts()));
+        return;
+    }
+
+    let mut shortcuts = init_shortcuts();
+    config::merge_user_shortcuts(&mut shortcuts);
+
+    if args.interactive {
+        if io::stdout().is_terminal() {
+            if let Err(e) = tui::run(&shortcuts) {
+                eprintln!("Error: {}", e);
+                std::process::exit(1);
+            }
+        } else {
+            print!("{}", build_output(&args, &shortcuts));
+        }
+        return;
+    }
+
+    if let Some(chord) = &args.key {
+        let hits = explain_key(&shortcuts, chord);
+        if hits.is_empty() {
+            println!("No shortcut bound to '{}'", chord);
+        } else {
+            print!("{}", format_key_table(&hits, chord));
+        }
+        return;
+    }
+
+    if let Some(query) = &args.find {
+        let hits = search_shortcuts(&shortcuts, query);
+        if hits.is_empty() {
+            println!("No shortcuts matching '{}'", query);
+        } else {
+            print!("{}", format_search_table(&hits));
+        }
+        return;
+    }
+
     print!("{}", build_output(&args, &shortcuts));
 }
 
@@ -236,6 +495,10 @@ mod tests {
             edit: false,
             recall: false,
             process: false,
+            find: None,
+            key: None,
+            vi: false,
+            interactive: false,
             uninstall: false,
         };
 
@@ -255,6 +518,10 @@ mod tests {
             edit: false,
             recall: false,
             process: false,
+            find: None,
+            key: None,
+            vi: false,
+            interactive: false,
             uninstall: false,
         };
 
@@ -272,6 +539,10 @@ mod tests {
             edit: true,
             recall: false,
             process: false,
+            find: None,
+            key: None,
+            vi: false,
+            interactive: false,
             uninstall: false,
         };
 
@@ -281,4 +552,62 @@ mod tests {
         assert!(output.contains("Edit related shortcuts"));
         assert!(!output.contains("Recall related shortcuts"));
     }
+
+    #[test]
+    fn test_fuzzy_score_matches_subsequence() {
+        assert!(fuzzy_score("ctl", "ctrl+l").is_some());
+        assert!(fuzzy_score("xyz", "ctrl+l").is_none());
+    }
+
+    #[test]
+    fn test_fuzzy_score_prefers_consecutive_and_boundary_matches() {
+        let consecutive = fuzzy_score("ctrl", "ctrl+a").unwrap();
+        let scattered = fuzzy_score("ctrl", "c a t unusedletters r l").unwrap();
+        assert!(consecutive > scattered);
+
+        let boundary = fuzzy_score("d", "ctrl+d").unwrap();
+        let mid_word = fuzzy_score("d", "ctrl_d").unwrap();
+        assert!(boundary > mid_word);
+    }
+
+    #[test]
+    fn test_search_shortcuts_ranks_and_filters() {
+        let shortcuts = init_shortcuts();
+
+        let hits = search_shortcuts(&shortcuts, "clipboard");
+        assert!(!hits.is_empty());
+        assert!(hits.iter().all(|h| h.description.contains("clipboard")));
+
+        let no_hits = search_shortcuts(&shortcuts, "zzzzzzzz");
+        assert!(no_hits.is_empty());
+    }
+
+    #[test]
+    fn test_vi_output_shows_normal_and_insert_tables() {
+        let shortcuts = init_vi_shortcuts();
+        let output = build_vi_output(&shortcuts);
+
+        assert!(output.contains("Vi Normal related shortcuts"));
+        assert!(output.contains("Vi Insert related shortcuts"));
+    }
+
+    #[test]
+    fn test_normalize_chord_accepts_equivalent_spellings() {
+        assert_eq!(normalize_chord("ctrl+a"), "ctrl+a");
+        assert_eq!(normalize_chord("C-a"), "ctrl+a");
+        assert_eq!(normalize_chord("^a"), "ctrl+a");
+        assert_eq!(normalize_chord("M-."), "alt+.");
+    }
+
+    #[test]
+    fn test_explain_key_reports_all_matching_categories() {
+        let shortcuts = init_shortcuts();
+
+        let hits = explain_key(&shortcuts, "C-s");
+        let categories: Vec<&str> = hits.iter().map(|h| h.category.as_str()).collect();
+        assert!(categories.contains(&"Recall"));
+        assert!(categories.contains(&"Process"));
+
+        assert!(explain_key(&shortcuts, "ctrl+zzzzz").is_empty());
+    }
 }
@@ -0,0 +1,290 @@
+use std::collections::HashMap;
+use std::io::{self, Write};
+
+use crossterm::{
+    cursor, execute, queue,
+    event::{self, Event, KeyCode, KeyModifiers},
+    terminal::{self, ClearType},
+};
+
+use crate::{Shortcut, fuzzy_score};
+
+/// Header/separator/footer lines `render` prints around the row window.
+const CHROME_LINES: usize = 4;
+
+struct Row {
+    category: String,
+    key: String,
+    description: String,
+}
+
+enum Line<'a> {
+    Header(&'a str),
+    Entry(&'a Row),
+}
+
+/// Rows matching `query`, sorted by descending score, then category, then key;
+/// an empty query keeps everything. When scores differ, one category's rows
+/// can be split into several runs.
+fn ranked_rows(shortcuts: &HashMap<String, Vec<Shortcut>>, query: &str) -> Vec<Row> {
+    let mut rows: Vec<(i32, Row)> = shortcuts
+        .iter()
+        .flat_map(|(category, entries)| entries.iter().map(move |s| (category, s)))
+        .filter_map(|(category, s)| {
+            let score = if query.is_empty() {
+                0
+            } else {
+                let key_score = fuzzy_score(query, &s.key);
+                let desc_score = fuzzy_score(query, &s.description);
+                key_score.into_iter().chain(desc_score).max()?
+            };
+            Some((
+                score,
+                Row {
+                    category: category.clone(),
+                    key: s.key.clone(),
+                    description: s.description.clone(),
+                },
+            ))
+        })
+        .collect();
+
+    rows.sort_by(|(score_a, a), (score_b, b)| {
+        score_b
+            .cmp(score_a)
+            .then_with(|| a.category.cmp(&b.category))
+            .then_with(|| a.key.cmp(&b.key))
+    });
+
+    rows.into_iter().map(|(_, row)| row).collect()
+}
+
+/// Flattens `rows` into category-header and entry lines in display order.
+fn build_lines(rows: &[Row]) -> Vec<Line<'_>> {
+    let mut lines = Vec::new();
+    let mut last_category: Option<&str> = None;
+
+    for row in rows {
+        if last_category != Some(row.category.as_str()) {
+            lines.push(Line::Header(&row.category));
+            last_category = Some(row.category.as_str());
+        }
+        lines.push(Line::Entry(row));
+    }
+
+    lines
+}
+
+fn viewport_height() -> usize {
+    let total = terminal::size().map(|(_, rows)| rows as usize).unwrap_or(24);
+    total.saturating_sub(CHROME_LINES).max(1)
+}
+
+/// Slice of `lines` to draw, scrolled to keep entry `selected` visible.
+fn scrolled_window<'a>(
+    lines: &'a [Line<'a>],
+    selected: usize,
+    scroll_offset: &mut usize,
+    height: usize,
+) -> &'a [Line<'a>] {
+    let mut entry_seen = 0;
+    let selected_line = lines
+        .iter()
+        .position(|line| match line {
+            Line::Entry(_) => {
+                let is_selected = entry_seen == selected;
+                entry_seen += 1;
+                is_selected
+            }
+            Line::Header(_) => false,
+        })
+        .unwrap_or(0);
+
+    if selected_line < *scroll_offset {
+        *scroll_offset = selected_line;
+    } else if selected_line >= *scroll_offset + height {
+        *scroll_offset = selected_line + 1 - height;
+    }
+    *scroll_offset = (*scroll_offset).min(lines.len().saturating_sub(height));
+
+    let end = (*scroll_offset + height).min(lines.len());
+    &lines[*scroll_offset..end]
+}
+
+fn render(
+    stdout: &mut impl Write,
+    rows: &[Row],
+    query: &str,
+    selected: usize,
+    scroll_offset: &mut usize,
+) -> io::Result<()> {
+    let lines = build_lines(rows);
+    let window = scrolled_window(&lines, selected, scroll_offset, viewport_height());
+
+    queue!(stdout, cursor::MoveTo(0, 0), terminal::Clear(ClearType::All))?;
+    write!(stdout, "Find: {}\r\n", query)?;
+    write!(stdout, "{}\r\n", "-".repeat(60))?;
+
+    let mut entry_index = scroll_offset_entry_count(&lines, *scroll_offset);
+    for line in window {
+        match line {
+            Line::Header(category) => write!(stdout, "-- {} --\r\n", category)?,
+            Line::Entry(row) => {
+                let marker = if entry_index == selected { ">" } else { " " };
+                write!(stdout, "{} {:<20} {}\r\n", marker, row.key, row.description)?;
+                entry_index += 1;
+            }
+        }
+    }
+
+    write!(
+        stdout,
+        "\r\n(type to filter, ctrl+n/ctrl+p or arrows to move, esc/ctrl+g to quit)\r\n"
+    )?;
+    stdout.flush()
+}
+
+/// How many `Line::Entry`s precede `scroll_offset` in `lines`.
+fn scroll_offset_entry_count(lines: &[Line], scroll_offset: usize) -> usize {
+    lines[..scroll_offset]
+        .iter()
+        .filter(|line| matches!(line, Line::Entry(_)))
+        .count()
+}
+
+/// Runs the `-i` interactive shortcut browser.
+pub fn run(shortcuts: &HashMap<String, Vec<Shortcut>>) -> io::Result<()> {
+    terminal::enable_raw_mode()?;
+    let mut stdout = io::stdout();
+    execute!(stdout, terminal::EnterAlternateScreen, cursor::Hide)?;
+
+    let result = event_loop(&mut stdout, shortcuts);
+
+    execute!(stdout, cursor::Show, terminal::LeaveAlternateScreen)?;
+    terminal::disable_raw_mode()?;
+    result
+}
+
+fn event_loop(stdout: &mut impl Write, shortcuts: &HashMap<String, Vec<Shortcut>>) -> io::Result<()> {
+    let mut query = String::new();
+    let mut selected = 0usize;
+    let mut scroll_offset = 0usize;
+    let mut rows = ranked_rows(shortcuts, &query);
+    render(stdout, &rows, &query, selected, &mut scroll_offset)?;
+
+    loop {
+        let Event::Key(key) = event::read()? else {
+            continue;
+        };
+
+        let is_quit = key.code == KeyCode::Esc
+            || (key.code == KeyCode::Char('g') && key.modifiers.contains(KeyModifiers::CONTROL));
+        if is_quit {
+            return Ok(());
+        }
+
+        let mut query_changed = false;
+        match key.code {
+            KeyCode::Char('n') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                selected = selected.saturating_add(1);
+            }
+            KeyCode::Char('p') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                selected = selected.saturating_sub(1);
+            }
+            KeyCode::Down => selected = selected.saturating_add(1),
+            KeyCode::Up => selected = selected.saturating_sub(1),
+            KeyCode::Backspace => {
+                query.pop();
+                query_changed = true;
+            }
+            KeyCode::Char(c) if !key.modifiers.contains(KeyModifiers::CONTROL) => {
+                query.push(c);
+                query_changed = true;
+            }
+            _ => {}
+        }
+
+        if query_changed {
+            rows = ranked_rows(shortcuts, &query);
+            selected = 0;
+            scroll_offset = 0;
+        }
+        selected = if rows.is_empty() {
+            0
+        } else {
+            selected.min(rows.len() - 1)
+        };
+
+        render(stdout, &rows, &query, selected, &mut scroll_offset)?;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ranked_rows_filters_by_query() {
+        let shortcuts = HashMap::from([(
+            "Movement".to_string(),
+            vec![
+                Shortcut::new("ctrl+a", "Go to line start (home)"),
+                Shortcut::new("ctrl+e", "Go to line end (end)"),
+            ],
+        )]);
+
+        let rows = ranked_rows(&shortcuts, "start");
+        assert_eq!(rows.len(), 1);
+        assert_eq!(rows[0].key, "ctrl+a");
+    }
+
+    #[test]
+    fn test_ranked_rows_keeps_everything_on_empty_query() {
+        let shortcuts = HashMap::from([(
+            "Movement".to_string(),
+            vec![
+                Shortcut::new("ctrl+a", "Go to line start (home)"),
+                Shortcut::new("ctrl+e", "Go to line end (end)"),
+            ],
+        )]);
+
+        assert_eq!(ranked_rows(&shortcuts, "").len(), 2);
+    }
+
+    #[test]
+    fn test_scrolled_window_keeps_selected_entry_visible() {
+        let rows: Vec<Row> = (0..20)
+            .map(|i| Row {
+                category: "Movement".to_string(),
+                key: format!("key{i}"),
+                description: format!("desc{i}"),
+            })
+            .collect();
+        let lines = build_lines(&rows);
+        let mut scroll_offset = 0usize;
+
+        // Selecting an entry far past the viewport must scroll to it.
+        let window = scrolled_window(&lines, 15, &mut scroll_offset, 5);
+        let entry_count = window
+            .iter()
+            .filter(|line| matches!(line, Line::Entry(_)))
+            .count();
+        assert!(entry_count <= 5);
+        assert!(scroll_offset > 0);
+    }
+
+    #[test]
+    fn test_selected_index_is_clamped_when_rows_shrink() {
+        let shortcuts = HashMap::from([(
+            "Movement".to_string(),
+            vec![
+                Shortcut::new("ctrl+a", "Go to line start (home)"),
+                Shortcut::new("ctrl+e", "Go to line end (end)"),
+            ],
+        )]);
+
+        let rows = ranked_rows(&shortcuts, "start");
+        let selected = 5usize.min(rows.len().saturating_sub(1));
+        assert_eq!(selected, 0);
+    }
+}